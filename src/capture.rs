@@ -0,0 +1,744 @@
+//! Low-level ScreenCaptureKit plumbing shared by [`crate::monitor`] and [`crate::window`]
+//!
+//! This module owns everything that talks to `cidre::sc` directly: fetching
+//! shareable content, one-shot image capture, and the continuous [`Stream`]
+//! API. [`Monitor`](crate::Monitor) and [`Window`](crate::Window) are thin,
+//! `Result`-returning wrappers around the functions here.
+
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Condvar, Mutex};
+
+use cidre::{cg, define_obj_type, dispatch, objc, sc};
+use image::RgbaImage;
+use tracing::{debug, warn};
+
+use crate::error::{XCapError, XCapResult};
+
+/// Fetch the current shareable content (displays + windows) from SCK.
+///
+/// `SCShareableContent` is delivered asynchronously via a completion
+/// handler; this blocks the calling thread on a one-shot channel so the rest
+/// of the crate can stay synchronous, matching the rest of this API.
+pub(crate) fn get_shareable_content() -> XCapResult<sc::ShareableContent> {
+    let (tx, rx) = std::sync::mpsc::channel();
+
+    sc::ShareableContent::current_with_completion(move |content, err| {
+        let _ = tx.send(content.ok_or(()).map_err(|_| {
+            err.map(|e| e.to_string())
+                .unwrap_or_else(|| "unknown SCShareableContent error".into())
+        }));
+    });
+
+    rx.recv()
+        .map_err(|_| XCapError::new("shareable content callback dropped"))?
+        .map_err(|msg| {
+            if msg.to_lowercase().contains("permission") || msg.to_lowercase().contains("denied") {
+                XCapError::permission_denied()
+            } else {
+                XCapError::new(format!("failed to fetch shareable content: {msg}"))
+            }
+        })
+}
+
+/// Capture a single frame of `display_id` synchronously and decode it into an [`RgbaImage`].
+pub(crate) fn capture_monitor_sync(display_id: u32, width: u32, height: u32) -> XCapResult<RgbaImage> {
+    let content = get_shareable_content()?;
+    let display = content
+        .displays()
+        .iter()
+        .find(|d| d.display_id().0 == display_id)
+        .ok_or_else(|| XCapError::new(format!("display {display_id} is no longer available")))?;
+
+    let filter = sc::ContentFilter::with_display_excluding_windows(display, &[]);
+    capture_filter_sync(&filter, width, height, false)
+}
+
+/// Capture a single frame of `window_id` synchronously and decode it into an [`RgbaImage`].
+pub(crate) fn capture_window_sync(window_id: u32, width: u32, height: u32) -> XCapResult<RgbaImage> {
+    let content = get_shareable_content()?;
+    let window = content
+        .windows()
+        .iter()
+        .find(|w| w.id() == window_id)
+        .ok_or_else(|| XCapError::new(format!("window {window_id} is no longer available")))?;
+
+    let filter = sc::ContentFilter::with_desktop_independent_window(window);
+    capture_filter_sync(&filter, width, height, true)
+}
+
+fn capture_filter_sync(
+    filter: &sc::ContentFilter,
+    width: u32,
+    height: u32,
+    shows_cursor: bool,
+) -> XCapResult<RgbaImage> {
+    let mut config = sc::StreamConfiguration::new();
+    config.set_width(width as usize);
+    config.set_height(height as usize);
+    config.set_shows_cursor(shows_cursor);
+
+    screenshot(filter, &config)
+}
+
+/// Capture a cropped sub-region of `display_id` synchronously and decode it into an [`RgbaImage`].
+pub(crate) fn capture_monitor_region_sync(display_id: u32, rect: cg::Rect) -> XCapResult<RgbaImage> {
+    let content = get_shareable_content()?;
+    let display = content
+        .displays()
+        .iter()
+        .find(|d| d.display_id().0 == display_id)
+        .ok_or_else(|| XCapError::new(format!("display {display_id} is no longer available")))?;
+
+    let filter = sc::ContentFilter::with_display_excluding_windows(display, &[]);
+    capture_filter_region_sync(&filter, rect, false)
+}
+
+/// Capture a cropped sub-region of `window_id` synchronously and decode it into an [`RgbaImage`].
+pub(crate) fn capture_window_region_sync(window_id: u32, rect: cg::Rect) -> XCapResult<RgbaImage> {
+    let content = get_shareable_content()?;
+    let window = content
+        .windows()
+        .iter()
+        .find(|w| w.id() == window_id)
+        .ok_or_else(|| XCapError::new(format!("window {window_id} is no longer available")))?;
+
+    let filter = sc::ContentFilter::with_desktop_independent_window(window);
+    capture_filter_region_sync(&filter, rect, true)
+}
+
+/// Like [`capture_filter_sync`], but crops to `rect` via the stream
+/// configuration's source rect before pixel copy rather than capturing the
+/// full frame and slicing it afterwards.
+fn capture_filter_region_sync(
+    filter: &sc::ContentFilter,
+    rect: cg::Rect,
+    shows_cursor: bool,
+) -> XCapResult<RgbaImage> {
+    let mut config = sc::StreamConfiguration::new();
+    config.set_width(rect.size.width as usize);
+    config.set_height(rect.size.height as usize);
+    config.set_shows_cursor(shows_cursor);
+    config.set_source_rect(rect);
+
+    screenshot(filter, &config)
+}
+
+/// Drive `SCScreenshotManager` for a single still image and decode it.
+fn screenshot(filter: &sc::ContentFilter, config: &sc::StreamConfiguration) -> XCapResult<RgbaImage> {
+    let (tx, rx) = std::sync::mpsc::channel();
+
+    sc::ScreenshotManager::capture_image_with_completion(filter, config, move |image, err| {
+        let _ = tx.send(image.ok_or_else(|| {
+            err.map(|e| e.to_string())
+                .unwrap_or_else(|| "unknown SCScreenshotManager error".into())
+        }));
+    });
+
+    let cg_image = rx
+        .recv()
+        .map_err(|_| XCapError::new("screenshot callback dropped"))?
+        .map_err(|msg| {
+            if msg.to_lowercase().contains("permission") || msg.to_lowercase().contains("denied") {
+                XCapError::permission_denied()
+            } else {
+                XCapError::new(format!("capture failed: {msg}"))
+            }
+        })?;
+
+    cg_image_to_rgba(&cg_image)
+}
+
+fn cg_image_to_rgba(image: &cg::Image) -> XCapResult<RgbaImage> {
+    let width = image.width() as u32;
+    let height = image.height() as u32;
+    let rgba = image
+        .to_rgba8()
+        .map_err(|e| XCapError::new(format!("failed to decode captured frame: {e}")))?;
+
+    RgbaImage::from_raw(width, height, rgba)
+        .ok_or_else(|| XCapError::new("captured frame had an unexpected byte layout"))
+}
+
+/// Start a continuous stream of `display_id`, delivering frames over a bounded channel.
+pub(crate) fn start_monitor_stream(
+    display_id: u32,
+    config: &StreamConfig,
+) -> XCapResult<(Stream, FrameReceiver)> {
+    let content = get_shareable_content()?;
+    let display = content
+        .displays()
+        .iter()
+        .find(|d| d.display_id().0 == display_id)
+        .ok_or_else(|| XCapError::new(format!("display {display_id} is no longer available")))?;
+    let filter = sc::ContentFilter::with_display_excluding_windows(display, &[]);
+    Stream::start_with_channel(&filter, config)
+}
+
+/// Start a continuous stream of `display_id`, delivering frames to `callback`.
+pub(crate) fn start_monitor_stream_with_callback<F>(
+    display_id: u32,
+    config: &StreamConfig,
+    callback: F,
+) -> XCapResult<Stream>
+where
+    F: FnMut(Frame) + Send + 'static,
+{
+    let content = get_shareable_content()?;
+    let display = content
+        .displays()
+        .iter()
+        .find(|d| d.display_id().0 == display_id)
+        .ok_or_else(|| XCapError::new(format!("display {display_id} is no longer available")))?;
+    let filter = sc::ContentFilter::with_display_excluding_windows(display, &[]);
+    Stream::start_with_callback(&filter, config, callback)
+}
+
+/// Start a continuous stream of `window_id`, delivering frames over a bounded channel.
+pub(crate) fn start_window_stream(
+    window_id: u32,
+    config: &StreamConfig,
+) -> XCapResult<(Stream, FrameReceiver)> {
+    let content = get_shareable_content()?;
+    let window = content
+        .windows()
+        .iter()
+        .find(|w| w.id() == window_id)
+        .ok_or_else(|| XCapError::new(format!("window {window_id} is no longer available")))?;
+    let filter = sc::ContentFilter::with_desktop_independent_window(window);
+    Stream::start_with_channel(&filter, config)
+}
+
+/// Start a continuous stream of `window_id`, delivering frames to `callback`.
+pub(crate) fn start_window_stream_with_callback<F>(
+    window_id: u32,
+    config: &StreamConfig,
+    callback: F,
+) -> XCapResult<Stream>
+where
+    F: FnMut(Frame) + Send + 'static,
+{
+    let content = get_shareable_content()?;
+    let window = content
+        .windows()
+        .iter()
+        .find(|w| w.id() == window_id)
+        .ok_or_else(|| XCapError::new(format!("window {window_id} is no longer available")))?;
+    let filter = sc::ContentFilter::with_desktop_independent_window(window);
+    Stream::start_with_callback(&filter, config, callback)
+}
+
+/// Pixel format requested for delivered frames, mirroring a subset of `SCStreamPixelFormat`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PixelFormat {
+    /// 32-bit BGRA. What SCK delivers by default, and the only format
+    /// [`Frame::to_image`] currently knows how to decode.
+    #[default]
+    Bgra8888,
+    /// Bi-planar 4:2:0 video-range YCbCr (`'420v'`). Accepted by SCK for
+    /// callers that want to feed frames straight into a video encoder, but
+    /// not yet decoded by [`Frame::to_image`].
+    Nv12,
+}
+
+/// A sub-region to crop a capture to, in the same units as the source's
+/// `width()`/`height()`, which `capture_region` validates it against
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CaptureRect {
+    /// Left edge, in pixels from the source's origin
+    pub x: u32,
+    /// Top edge, in pixels from the source's origin
+    pub y: u32,
+    /// Region width in pixels
+    pub width: u32,
+    /// Region height in pixels
+    pub height: u32,
+}
+
+/// Validate `rect` against a source of `max_width` x `max_height` and convert it to a `CGRect`.
+pub(crate) fn validate_capture_rect(
+    rect: CaptureRect,
+    max_width: u32,
+    max_height: u32,
+) -> XCapResult<cg::Rect> {
+    if rect.width == 0 || rect.height == 0 {
+        return Err(XCapError::new("capture region must not be empty"));
+    }
+
+    let right = rect
+        .x
+        .checked_add(rect.width)
+        .ok_or_else(|| XCapError::new("capture region overflows"))?;
+    let bottom = rect
+        .y
+        .checked_add(rect.height)
+        .ok_or_else(|| XCapError::new("capture region overflows"))?;
+
+    if right > max_width || bottom > max_height {
+        return Err(XCapError::new(format!(
+            "capture region ({}, {}, {}, {}) is out of bounds for a {}x{} source",
+            rect.x, rect.y, rect.width, rect.height, max_width, max_height
+        )));
+    }
+
+    Ok(cg::Rect::new(
+        rect.x as f64,
+        rect.y as f64,
+        rect.width as f64,
+        rect.height as f64,
+    ))
+}
+
+/// Configuration for a continuous [`Monitor::start_stream`](crate::Monitor::start_stream) /
+/// [`Window::start_stream`](crate::Window::start_stream) session.
+///
+/// Mirrors the subset of `SCStreamConfiguration` callers actually need.
+/// The [`Default`] is 30 fps, cursor shown, [`PixelFormat::Bgra8888`], a
+/// queue depth of 1, and no explicit output size.
+#[derive(Debug, Clone, Copy)]
+pub struct StreamConfig {
+    /// Target frames per second. SCK treats this as an upper bound, not a guarantee.
+    pub fps: f64,
+    /// Output width in pixels. Applied only together with `height`; if
+    /// either is `None`, the output size is left to SCK.
+    pub width: Option<u32>,
+    /// Output height in pixels. Applied only together with `width`; if
+    /// either is `None`, the output size is left to SCK.
+    pub height: Option<u32>,
+    /// Whether the cursor should be composited into delivered frames.
+    pub shows_cursor: bool,
+    /// Pixel format SCK should deliver frames in.
+    pub pixel_format: PixelFormat,
+    /// How many undelivered frames may queue before the oldest is dropped
+    /// in favor of the newest, instead of blocking the SCK delegate queue
+    /// or buffering without bound.
+    pub queue_depth: usize,
+}
+
+impl Default for StreamConfig {
+    fn default() -> Self {
+        StreamConfig {
+            fps: 30.0,
+            width: None,
+            height: None,
+            shows_cursor: true,
+            pixel_format: PixelFormat::default(),
+            queue_depth: 1,
+        }
+    }
+}
+
+/// A single decoded (or decodable) frame delivered by a [`Stream`].
+pub struct Frame {
+    /// Presentation timestamp of the backing `CMSampleBuffer`, in seconds.
+    pub timestamp: f64,
+    width: u32,
+    height: u32,
+    pixel_format: PixelFormat,
+    pixel_buffer: cidre::cv::ImageBufRetained,
+}
+
+impl Frame {
+    /// Frame width in pixels.
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Frame height in pixels.
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Decode this frame into an [`RgbaImage`].
+    ///
+    /// Decoding is deferred to this call (rather than done eagerly on the
+    /// SCK delegate queue) so a consumer that only inspects `timestamp` for
+    /// pacing never pays for a pixel copy. Only [`PixelFormat::Bgra8888`] is
+    /// currently supported; a stream configured for another format returns
+    /// an error here instead of garbage pixels.
+    pub fn to_image(&self) -> XCapResult<RgbaImage> {
+        match self.pixel_format {
+            PixelFormat::Bgra8888 => pixel_buffer_to_rgba(&self.pixel_buffer, self.width, self.height),
+            PixelFormat::Nv12 => Err(XCapError::new(
+                "Frame::to_image does not yet support PixelFormat::Nv12; request Bgra8888 to decode frames",
+            )),
+        }
+    }
+}
+
+fn pixel_buffer_to_rgba(
+    pixel_buffer: &cidre::cv::ImageBufRetained,
+    width: u32,
+    height: u32,
+) -> XCapResult<RgbaImage> {
+    pixel_buffer
+        .lock_base_addr(Default::default())
+        .map_err(|e| XCapError::new(format!("failed to lock pixel buffer: {e}")))?;
+    let bytes_per_row = pixel_buffer.bytes_per_row();
+    let padded = pixel_buffer.bgra_bytes_copy();
+    pixel_buffer.unlock_base_addr(Default::default());
+
+    // `bgra_bytes_copy` preserves CVPixelBuffer's row stride, which is
+    // frequently wider than `width * 4` bytes for alignment - strip that
+    // padding before handing a tightly-packed buffer to `RgbaImage::from_raw`.
+    let row_bytes = width as usize * 4;
+    let mut rgba = Vec::with_capacity(row_bytes * height as usize);
+    for row in padded.chunks_exact(bytes_per_row).take(height as usize) {
+        rgba.extend_from_slice(&row[..row_bytes]);
+    }
+
+    // SCK delivers BGRA; re-pack to the RGBA byte order `image::RgbaImage` expects.
+    for px in rgba.chunks_exact_mut(4) {
+        px.swap(0, 2);
+    }
+
+    RgbaImage::from_raw(width, height, rgba)
+        .ok_or_else(|| XCapError::new("captured frame had an unexpected byte layout"))
+}
+
+fn pixel_format_to_cv(format: PixelFormat) -> cidre::cv::PixelFormat {
+    match format {
+        PixelFormat::Bgra8888 => cidre::cv::PixelFormat::_32_BGRA,
+        PixelFormat::Nv12 => cidre::cv::PixelFormat::_420V,
+    }
+}
+
+/// Shared state behind a [`FrameSender`]/[`FrameReceiver`] pair.
+///
+/// Unlike `std::sync::mpsc`, `push` replaces the *oldest* queued frame when
+/// the channel is full instead of rejecting the *newest* one — the whole
+/// point of coalescing is that a lagging consumer catches back up to the
+/// latest frame rather than draining an ever-growing backlog of stale ones.
+struct FrameChannel {
+    queue: Mutex<VecDeque<Frame>>,
+    condvar: Condvar,
+    depth: usize,
+    receiver_alive: AtomicBool,
+    sender_alive: AtomicBool,
+}
+
+fn frame_channel(depth: usize) -> (FrameSender, FrameReceiver) {
+    let inner = Arc::new(FrameChannel {
+        queue: Mutex::new(VecDeque::with_capacity(depth.max(1))),
+        condvar: Condvar::new(),
+        depth: depth.max(1),
+        receiver_alive: AtomicBool::new(true),
+        sender_alive: AtomicBool::new(true),
+    });
+    (
+        FrameSender {
+            inner: inner.clone(),
+        },
+        FrameReceiver { inner },
+    )
+}
+
+struct FrameSender {
+    inner: Arc<FrameChannel>,
+}
+
+impl FrameSender {
+    /// Push a frame, dropping the oldest queued one if the channel is already full.
+    ///
+    /// Returns `false` if the receiving end has been dropped, so the caller
+    /// knows to stop capture instead of silently pushing into the void.
+    fn push(&self, frame: Frame) -> bool {
+        if !self.inner.receiver_alive.load(Ordering::Acquire) {
+            return false;
+        }
+
+        let mut queue = self.inner.queue.lock().expect("frame queue mutex poisoned");
+        if queue.len() >= self.inner.depth {
+            queue.pop_front();
+        }
+        queue.push_back(frame);
+        drop(queue);
+        self.inner.condvar.notify_one();
+        true
+    }
+}
+
+impl Drop for FrameSender {
+    fn drop(&mut self) {
+        self.inner.sender_alive.store(false, Ordering::Release);
+        self.inner.condvar.notify_all();
+    }
+}
+
+/// Receiving half of a [`Stream`]'s channel, returned by
+/// [`Monitor::start_stream`](crate::Monitor::start_stream) /
+/// [`Window::start_stream`](crate::Window::start_stream).
+///
+/// Dropping this (while keeping the paired [`Stream`] alive) stops capture
+/// as soon as the next frame would otherwise be delivered.
+pub struct FrameReceiver {
+    inner: Arc<FrameChannel>,
+}
+
+impl FrameReceiver {
+    /// Block until a frame is available, or return `None` once the stream has stopped.
+    pub fn recv(&self) -> Option<Frame> {
+        let mut queue = self.inner.queue.lock().expect("frame queue mutex poisoned");
+        loop {
+            if let Some(frame) = queue.pop_front() {
+                return Some(frame);
+            }
+            if !self.inner.sender_alive.load(Ordering::Acquire) {
+                return None;
+            }
+            queue = self
+                .inner
+                .condvar
+                .wait(queue)
+                .expect("frame queue mutex poisoned");
+        }
+    }
+
+    /// Return the oldest queued frame without blocking, if one is available.
+    ///
+    /// Frames are still delivered in order, same as [`FrameReceiver::recv`];
+    /// [`FrameSender::push`] never reorders them, it only evicts the oldest
+    /// queued frame once the channel is full. Lower `queue_depth` in
+    /// [`StreamConfig`] if you want this to skip ahead to more recent frames
+    /// when the consumer falls behind.
+    pub fn try_recv(&self) -> Option<Frame> {
+        self.inner.queue.lock().expect("frame queue mutex poisoned").pop_front()
+    }
+}
+
+impl Drop for FrameReceiver {
+    fn drop(&mut self) {
+        self.inner.receiver_alive.store(false, Ordering::Release);
+    }
+}
+
+/// Where decoded frames from a [`Stream`] are sent.
+enum FrameSink {
+    Callback(Box<dyn FnMut(Frame) + Send>),
+    Channel(FrameSender),
+}
+
+/// State shared with the Objective-C delegate: where frames go, plus a
+/// clone of the `SCStream` handle so the delegate can stop capture itself
+/// if it notices the channel's receiver has been dropped.
+struct StreamOutputState {
+    sink: Mutex<FrameSink>,
+    sc_stream: sc::StreamRetained,
+    pixel_format: PixelFormat,
+    stop_requested: AtomicBool,
+}
+
+define_obj_type!(StreamOutputDelegate + sc::StreamOutputImpl, Arc<StreamOutputState>, STREAM_OUTPUT_DELEGATE);
+
+impl sc::StreamOutput for StreamOutputDelegate {
+    extern "C" fn stream_did_output_sample_buf(
+        &mut self,
+        _stream: &sc::Stream,
+        sample_buf: &cidre::cm::SampleBuf,
+        kind: sc::OutputType,
+    ) {
+        if kind != sc::OutputType::Screen {
+            return;
+        }
+
+        let Some(pixel_buffer) = sample_buf.image_buf() else {
+            return;
+        };
+        let state = self.inner();
+        let timestamp = sample_buf.pts().as_secs_f64();
+        let width = pixel_buffer.width() as u32;
+        let height = pixel_buffer.height() as u32;
+        let frame = Frame {
+            timestamp,
+            width,
+            height,
+            pixel_format: state.pixel_format,
+            pixel_buffer: pixel_buffer.retained(),
+        };
+
+        let mut sink = state.sink.lock().expect("frame sink mutex poisoned");
+        let receiver_gone = match &mut *sink {
+            FrameSink::Callback(callback) => {
+                callback(frame);
+                false
+            }
+            FrameSink::Channel(sender) => !sender.push(frame),
+        };
+        drop(sink);
+
+        if receiver_gone && !state.stop_requested.swap(true, Ordering::AcqRel) {
+            debug!("stream receiver dropped; stopping capture");
+            state.sc_stream.stop_capture_with_completion(|_| {});
+        }
+    }
+}
+
+/// A running ScreenCaptureKit capture session.
+///
+/// Dropping a `Stream` stops capture and tears down the underlying
+/// `SCStream`; call [`Stream::stop`] explicitly to surface teardown errors.
+pub struct Stream {
+    sc_stream: sc::StreamRetained,
+    // Keeps the Objective-C delegate (and, for the channel variant, the
+    // sender half of the channel) alive for as long as the stream runs.
+    _delegate: objc::ObjRetained<StreamOutputDelegate>,
+    stopped: bool,
+}
+
+impl Stream {
+    fn start(filter: &sc::ContentFilter, config: &StreamConfig, sink: FrameSink) -> XCapResult<Self> {
+        let mut sc_config = sc::StreamConfiguration::new();
+        sc_config.set_minimum_frame_interval(cidre::cm::Time::new_with_secs(1.0 / config.fps.max(1.0)));
+        sc_config.set_shows_cursor(config.shows_cursor);
+        sc_config.set_pixel_format(pixel_format_to_cv(config.pixel_format));
+        if let (Some(width), Some(height)) = (config.width, config.height) {
+            sc_config.set_width(width as usize);
+            sc_config.set_height(height as usize);
+        }
+
+        let sc_stream = sc::Stream::with(filter, &sc_config, None);
+        let state = Arc::new(StreamOutputState {
+            sink: Mutex::new(sink),
+            sc_stream: sc_stream.retained(),
+            pixel_format: config.pixel_format,
+            stop_requested: AtomicBool::new(false),
+        });
+        let delegate = StreamOutputDelegate::with(state);
+
+        sc_stream
+            .add_stream_output(&delegate, sc::OutputType::Screen, &dispatch::Queue::serial("sck-rs.stream"))
+            .map_err(|e| XCapError::new(format!("failed to attach stream output: {e}")))?;
+
+        let (tx, rx) = std::sync::mpsc::channel();
+        sc_stream.start_capture_with_completion(move |err| {
+            let _ = tx.send(err.map(|e| e.to_string()));
+        });
+        if let Some(msg) = rx
+            .recv()
+            .map_err(|_| XCapError::new("start_capture callback dropped"))?
+        {
+            return Err(XCapError::new(format!("failed to start capture: {msg}")));
+        }
+
+        debug!("SCStream started");
+
+        Ok(Stream {
+            sc_stream,
+            _delegate: delegate,
+            stopped: false,
+        })
+    }
+
+    /// Start a stream delivering frames over a coalescing channel.
+    ///
+    /// The channel holds at most `config.queue_depth` undelivered frames;
+    /// once full, the oldest queued frame is dropped in favor of the
+    /// newest, so a lagging consumer catches back up instead of draining an
+    /// ever-growing backlog of stale frames. Dropping the returned
+    /// [`FrameReceiver`] (even while the [`Stream`] itself is still alive)
+    /// stops capture as soon as the next frame would otherwise be
+    /// delivered.
+    pub(crate) fn start_with_channel(
+        filter: &sc::ContentFilter,
+        config: &StreamConfig,
+    ) -> XCapResult<(Self, FrameReceiver)> {
+        let (tx, rx) = frame_channel(config.queue_depth);
+        let stream = Self::start(filter, config, FrameSink::Channel(tx))?;
+        Ok((stream, rx))
+    }
+
+    /// Start a stream delivering frames to `callback` on SCK's delegate queue.
+    pub(crate) fn start_with_callback<F>(
+        filter: &sc::ContentFilter,
+        config: &StreamConfig,
+        callback: F,
+    ) -> XCapResult<Self>
+    where
+        F: FnMut(Frame) + Send + 'static,
+    {
+        Self::start(filter, config, FrameSink::Callback(Box::new(callback)))
+    }
+
+    /// Stop capture and tear down the underlying `SCStream`.
+    ///
+    /// Safe to call more than once; subsequent calls are a no-op. Also
+    /// invoked automatically on drop.
+    pub fn stop(&mut self) -> XCapResult<()> {
+        if self.stopped {
+            return Ok(());
+        }
+        self.stopped = true;
+
+        // Drop the channel's sender so a blocked `FrameReceiver::recv` wakes
+        // up and returns `None` instead of waiting for frames that never come.
+        if let Ok(mut sink) = self._delegate.inner().sink.lock() {
+            *sink = FrameSink::Callback(Box::new(|_| {}));
+        }
+
+        let (tx, rx) = std::sync::mpsc::channel();
+        self.sc_stream.stop_capture_with_completion(move |err| {
+            let _ = tx.send(err.map(|e| e.to_string()));
+        });
+        if let Some(msg) = rx
+            .recv()
+            .map_err(|_| XCapError::new("stop_capture callback dropped"))?
+        {
+            return Err(XCapError::new(format!("failed to stop capture: {msg}")));
+        }
+        Ok(())
+    }
+}
+
+impl Drop for Stream {
+    fn drop(&mut self) {
+        if let Err(e) = self.stop() {
+            warn!("error stopping SCStream on drop: {e}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_capture_rect_in_bounds() {
+        let rect = CaptureRect {
+            x: 10,
+            y: 20,
+            width: 100,
+            height: 50,
+        };
+
+        let cg_rect = validate_capture_rect(rect, 1920, 1080).unwrap();
+        assert_eq!(cg_rect.origin.x, 10.0);
+        assert_eq!(cg_rect.origin.y, 20.0);
+        assert_eq!(cg_rect.size.width, 100.0);
+        assert_eq!(cg_rect.size.height, 50.0);
+    }
+
+    #[test]
+    fn test_validate_capture_rect_rejects_empty() {
+        let rect = CaptureRect {
+            x: 0,
+            y: 0,
+            width: 0,
+            height: 50,
+        };
+
+        assert!(validate_capture_rect(rect, 1920, 1080).is_err());
+    }
+
+    #[test]
+    fn test_validate_capture_rect_rejects_out_of_bounds() {
+        let rect = CaptureRect {
+            x: 1900,
+            y: 0,
+            width: 100,
+            height: 50,
+        };
+
+        assert!(validate_capture_rect(rect, 1920, 1080).is_err());
+    }
+}
+
@@ -0,0 +1,46 @@
+//! Error types returned by sck-rs
+
+use std::fmt;
+
+/// Result type alias used throughout sck-rs
+pub type XCapResult<T> = Result<T, XCapError>;
+
+/// Error returned by a capture operation
+#[derive(Debug, Clone)]
+pub struct XCapError {
+    message: String,
+}
+
+impl XCapError {
+    /// Construct an error with a custom message
+    pub fn new(message: impl Into<String>) -> Self {
+        XCapError {
+            message: message.into(),
+        }
+    }
+
+    /// No capturable monitors were found
+    pub fn no_monitors() -> Self {
+        XCapError::new("No monitors found")
+    }
+
+    /// No capturable windows were found
+    pub fn no_windows() -> Self {
+        XCapError::new("No windows found")
+    }
+
+    /// Screen recording permission was denied by the user
+    pub fn permission_denied() -> Self {
+        XCapError::new(
+            "Screen recording permission denied (System Settings > Privacy & Security > Screen Recording)",
+        )
+    }
+}
+
+impl fmt::Display for XCapError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for XCapError {}
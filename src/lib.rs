@@ -0,0 +1,15 @@
+//! sck-rs: macOS screen capture built on ScreenCaptureKit
+//!
+//! This crate mirrors the public API of the `xcap` crate so it can act as a
+//! drop-in replacement on macOS, while using Apple's ScreenCaptureKit (via
+//! the `cidre` bindings) instead of the deprecated CoreGraphics capture path.
+
+mod capture;
+mod error;
+mod monitor;
+mod window;
+
+pub use capture::{CaptureRect, Frame, FrameReceiver, PixelFormat, Stream, StreamConfig};
+pub use error::{XCapError, XCapResult};
+pub use monitor::{Monitor, VideoMode};
+pub use window::Window;
@@ -4,7 +4,7 @@ use cidre::cg;
 use image::RgbaImage;
 use tracing::debug;
 
-use crate::capture;
+use crate::capture::{self, CaptureRect, Frame, FrameReceiver, Stream, StreamConfig};
 use crate::error::{XCapError, XCapResult};
 
 /// Represents a capturable monitor/display
@@ -34,6 +34,19 @@ pub struct Monitor {
     is_primary: bool,
 }
 
+/// A single display mode reported by CoreGraphics
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VideoMode {
+    /// Pixel width of this mode
+    pub width: u32,
+    /// Pixel height of this mode
+    pub height: u32,
+    /// Bit depth of this mode (e.g. 32 for 8-bit RGBA)
+    pub bit_depth: u32,
+    /// Refresh rate of this mode, in Hz. `0.0` if CoreGraphics doesn't report one.
+    pub refresh_rate: f64,
+}
+
 impl Monitor {
     /// Get all available monitors
     ///
@@ -184,12 +197,128 @@ impl Monitor {
         self.is_primary
     }
 
+    /// Check whether `(x, y)` (in logical/desktop coordinates) falls within this monitor's bounds
+    pub fn contains_point(&self, x: i32, y: i32) -> bool {
+        x >= self.x
+            && y >= self.y
+            && x < self.x + self.logical_width as i32
+            && y < self.y + self.logical_height as i32
+    }
+
+    /// Get the display's current refresh rate, in Hz
+    ///
+    /// Reads `CGDisplayCopyDisplayMode` off the display's current mode.
+    /// Returns `0.0` on displays that don't report a refresh rate (e.g. some
+    /// virtual/AirPlay displays); such displays are refreshed on demand
+    /// rather than at a fixed interval.
+    pub fn refresh_rate(&self) -> XCapResult<f64> {
+        let cg_id = cg::DirectDisplayId(self.display_id);
+        let mode = cg_id
+            .display_mode()
+            .ok_or_else(|| XCapError::new(format!("no display mode for display {}", self.display_id)))?;
+
+        Ok(mode.refresh_rate())
+    }
+
+    /// Convert a point in this monitor's logical (desktop) coordinates to physical pixels
+    ///
+    /// `(x, y)` is relative to this monitor's own origin, not the desktop
+    /// origin; callers working with absolute desktop coordinates should
+    /// subtract `(self.x(), self.y())` first.
+    pub fn logical_to_physical(&self, point: (u32, u32)) -> (u32, u32) {
+        (
+            (point.0 as f64 * self.scale_factor).round() as u32,
+            (point.1 as f64 * self.scale_factor).round() as u32,
+        )
+    }
+
+    /// Convert a point in this monitor's physical pixels back to logical (desktop) coordinates
+    pub fn physical_to_logical(&self, point: (u32, u32)) -> (u32, u32) {
+        (
+            (point.0 as f64 / self.scale_factor).round() as u32,
+            (point.1 as f64 / self.scale_factor).round() as u32,
+        )
+    }
+
+    /// Get every video mode this display supports
+    ///
+    /// Reads `CGDisplayCopyAllDisplayModes` for the display backing this
+    /// monitor. Useful for picking a stream FPS that matches a mode the
+    /// display actually supports, or for detecting ProMotion/variable
+    /// refresh-rate displays (multiple modes with the same resolution but
+    /// different refresh rates).
+    pub fn video_modes(&self) -> XCapResult<Vec<VideoMode>> {
+        let cg_id = cg::DirectDisplayId(self.display_id);
+        let modes = cg_id.all_display_modes(None).ok_or_else(|| {
+            XCapError::new(format!("no display modes for display {}", self.display_id))
+        })?;
+
+        Ok(modes
+            .iter()
+            .map(|mode| VideoMode {
+                width: mode.pixels_wide() as u32,
+                height: mode.pixels_high() as u32,
+                bit_depth: mode.bit_depth() as u32,
+                refresh_rate: mode.refresh_rate(),
+            })
+            .collect())
+    }
+
     /// Capture an image of the monitor
     ///
     /// Returns an RGBA image of the entire monitor.
     pub fn capture_image(&self) -> XCapResult<RgbaImage> {
         capture::capture_monitor_sync(self.display_id, self.width, self.height)
     }
+
+    /// Capture a sub-region of the monitor, cropped to `rect`
+    ///
+    /// `rect` is in this monitor's physical pixels (see
+    /// [`Monitor::width`]/[`Monitor::height`]). Cropping happens via the
+    /// stream configuration's source rect before pixel copy, which matters on
+    /// large (e.g. 5K) displays.
+    pub fn capture_region(&self, rect: CaptureRect) -> XCapResult<RgbaImage> {
+        let cg_rect = capture::validate_capture_rect(rect, self.width, self.height)?;
+        capture::capture_monitor_region_sync(self.display_id, cg_rect)
+    }
+
+    /// Start a continuous capture stream of this monitor backed by `SCStream`.
+    ///
+    /// See [`Window::start_stream`](crate::Window::start_stream) for the
+    /// channel lifetime and coalescing semantics, which are identical here.
+    pub fn start_stream(&self, config: StreamConfig) -> XCapResult<(Stream, FrameReceiver)> {
+        capture::start_monitor_stream(self.display_id, &config)
+    }
+
+    /// Start a continuous capture stream of this monitor, delivering frames to `callback`.
+    ///
+    /// `callback` runs on SCK's delegate queue, not the caller's thread.
+    pub fn start_stream_with_callback<F>(&self, config: StreamConfig, callback: F) -> XCapResult<Stream>
+    where
+        F: FnMut(Frame) + Send + 'static,
+    {
+        capture::start_monitor_stream_with_callback(self.display_id, &config, callback)
+    }
+}
+
+/// Construct a `Monitor` from fixed fields, bypassing `Monitor::all()`.
+///
+/// Only available to in-crate unit tests that need a `Monitor` without
+/// screen recording permission (e.g. window/monitor overlap math).
+#[cfg(test)]
+pub(crate) fn test_monitor(x: i32, y: i32, logical_width: u32, logical_height: u32) -> Monitor {
+    Monitor {
+        display_id: 0,
+        name: String::new(),
+        x,
+        y,
+        width: logical_width,
+        height: logical_height,
+        logical_width,
+        logical_height,
+        scale_factor: 1.0,
+        is_primary: false,
+    }
 }
 
 #[cfg(test)]
@@ -230,4 +359,43 @@ mod tests {
         let result = Monitor::all();
         let _ = result;
     }
+
+    #[test]
+    fn test_logical_physical_roundtrip() {
+        let monitor = test_monitor(0, 0, 1920, 1080);
+        let mut retina = test_monitor(1920, 0, 1920, 1080);
+        retina.scale_factor = 2.0;
+
+        assert_eq!(monitor.logical_to_physical((100, 100)), (100, 100));
+        assert_eq!(retina.logical_to_physical((100, 100)), (200, 200));
+        assert_eq!(retina.physical_to_logical((200, 200)), (100, 100));
+    }
+
+    #[test]
+    fn test_contains_point_edges() {
+        let monitor = test_monitor(100, 100, 1920, 1080);
+
+        // Top-left corner is inclusive.
+        assert!(monitor.contains_point(100, 100));
+        assert!(!monitor.contains_point(99, 100));
+        assert!(!monitor.contains_point(100, 99));
+
+        // Bottom-right edge is exclusive (it's one past the last valid pixel).
+        assert!(monitor.contains_point(100 + 1920 - 1, 100 + 1080 - 1));
+        assert!(!monitor.contains_point(100 + 1920, 100 + 1080 - 1));
+        assert!(!monitor.contains_point(100 + 1920 - 1, 100 + 1080));
+    }
+
+    #[test]
+    fn test_video_mode_equality() {
+        let a = VideoMode {
+            width: 1920,
+            height: 1080,
+            bit_depth: 32,
+            refresh_rate: 60.0,
+        };
+        let b = a;
+
+        assert_eq!(a, b);
+    }
 }
@@ -18,8 +18,9 @@ fn get_frontmost_pid() -> i32 {
     -1
 }
 
-use crate::capture;
+use crate::capture::{self, CaptureRect, Frame, FrameReceiver, Stream, StreamConfig};
 use crate::error::{XCapError, XCapResult};
+use crate::monitor::Monitor;
 
 /// Represents a capturable window
 ///
@@ -182,10 +183,66 @@ impl Window {
         Ok(!self.is_on_screen)
     }
 
+    /// Height of the macOS menu bar, in logical points, that a merely-maximized
+    /// (as opposed to fullscreen) window leaves uncovered.
+    const MENU_BAR_INSET: u32 = 24;
+
+    /// Slack, in logical points, allowed when comparing a window's frame
+    /// against its monitor's bounds. Window managers sometimes leave a
+    /// sub-pixel gap even when a window is effectively maximized/fullscreen.
+    const FRAME_MATCH_TOLERANCE: i64 = 4;
+
     /// Check if the window is maximized
+    ///
+    /// A window is considered maximized when its frame fills its owning
+    /// monitor's visible area (screen size minus the menu bar inset) *and*
+    /// sits at that monitor's origin (just below the menu bar), but isn't in
+    /// a dedicated fullscreen space. See [`Window::is_fullscreen`].
     pub fn is_maximized(&self) -> XCapResult<bool> {
-        // TODO: Compare with monitor size
-        Ok(false)
+        if self.is_fullscreen()? {
+            return Ok(false);
+        }
+
+        let monitor = self.monitor()?;
+        let visible_height = monitor.logical_height().saturating_sub(Self::MENU_BAR_INSET);
+
+        Ok(Self::frame_matches(
+            self.width,
+            self.height,
+            monitor.logical_width(),
+            visible_height,
+        ) && (self.x - monitor.x()).abs() as i64 <= Self::FRAME_MATCH_TOLERANCE
+            && (self.y - (monitor.y() + Self::MENU_BAR_INSET as i32)).abs() as i64
+                <= Self::FRAME_MATCH_TOLERANCE)
+    }
+
+    /// Check if the window occupies a dedicated macOS fullscreen space
+    ///
+    /// A window is considered fullscreen when its frame exactly matches its
+    /// owning monitor's full bounds (menu bar included) and it's a normal
+    /// app-level window. A window that has entered its own fullscreen Space
+    /// is still layer 0 (see [`Window::window_layer`]) - it's `is_maximized`
+    /// that excludes the menu bar inset, which is what actually separates
+    /// the two. The layer check here isn't for the fullscreen Space itself;
+    /// it rules out a floating overlay/panel (layer > 0) that happens to
+    /// be sized to cover the whole screen.
+    pub fn is_fullscreen(&self) -> XCapResult<bool> {
+        let monitor = self.monitor()?;
+
+        let fills_monitor = Self::frame_matches(
+            self.width,
+            self.height,
+            monitor.logical_width(),
+            monitor.logical_height(),
+        ) && (self.x - monitor.x()).abs() as i64 <= Self::FRAME_MATCH_TOLERANCE
+            && (self.y - monitor.y()).abs() as i64 <= Self::FRAME_MATCH_TOLERANCE;
+
+        Ok(fills_monitor && self.window_layer == 0)
+    }
+
+    fn frame_matches(width: u32, height: u32, target_width: u32, target_height: u32) -> bool {
+        (width as i64 - target_width as i64).abs() <= Self::FRAME_MATCH_TOLERANCE
+            && (height as i64 - target_height as i64).abs() <= Self::FRAME_MATCH_TOLERANCE
     }
 
     /// Check if the window is focused
@@ -214,12 +271,133 @@ impl Window {
         self.window_layer
     }
 
+    /// Get the monitor this window mostly lives on
+    ///
+    /// Resolved by computing the overlap area between the window's frame
+    /// and every monitor's frame, then picking the monitor with the largest
+    /// overlap. Falls back to the primary monitor when the window doesn't
+    /// intersect any display (e.g. it was dragged fully offscreen).
+    pub fn monitor(&self) -> XCapResult<Monitor> {
+        let monitors = Monitor::all()?;
+
+        let best = monitors
+            .iter()
+            .map(|m| (m, overlap_area(self, m)))
+            .max_by_key(|(_, area)| *area)
+            .filter(|(_, area)| *area > 0)
+            .map(|(m, _)| m.clone());
+
+        match best {
+            Some(monitor) => Ok(monitor),
+            None => Monitor::primary(),
+        }
+    }
+
+    /// Get this window's frame in physical pixels, anchored to its owning monitor
+    ///
+    /// `width()`/`height()`/`x()`/`y()` are all in the same logical (desktop)
+    /// coordinate space macOS uses for window management, which is
+    /// ambiguous once you need to crop capture pixels on a mixed-DPI setup.
+    /// This resolves the window's owning monitor (see [`Window::monitor`])
+    /// and scales its frame by that monitor's `scale_factor`, returning
+    /// `(x, y, width, height)` in physical pixels relative to the monitor's
+    /// own physical origin.
+    pub fn frame_in_physical_pixels(&self) -> XCapResult<(i32, i32, u32, u32)> {
+        let monitor = self.monitor()?;
+        let scale = monitor.scale_factor();
+
+        // Position relative to the owning monitor's origin may be negative
+        // (a window straddling two monitors can start before this one
+        // begins), so scale it directly rather than via the unsigned
+        // logical_to_physical helper.
+        let relative_x = self.x - monitor.x();
+        let relative_y = self.y - monitor.y();
+        let physical_x = (relative_x as f64 * scale).round() as i32;
+        let physical_y = (relative_y as f64 * scale).round() as i32;
+
+        let (physical_width, physical_height) =
+            monitor.logical_to_physical((self.width, self.height));
+
+        Ok((physical_x, physical_y, physical_width, physical_height))
+    }
+
+    /// Get every monitor this window overlaps, ordered by overlap area (largest first)
+    ///
+    /// Useful for windows that straddle two displays, where a single
+    /// "owning" monitor isn't enough context for relative positioning.
+    pub fn monitors(&self) -> XCapResult<Vec<Monitor>> {
+        let mut monitors: Vec<(Monitor, i64)> = Monitor::all()?
+            .into_iter()
+            .map(|m| {
+                let area = overlap_area(self, &m);
+                (m, area)
+            })
+            .filter(|(_, area)| *area > 0)
+            .collect();
+
+        monitors.sort_by_key(|(_, area)| std::cmp::Reverse(*area));
+
+        Ok(monitors.into_iter().map(|(m, _)| m).collect())
+    }
+
     /// Capture an image of the window
     ///
     /// Returns an RGBA image of the window contents.
     pub fn capture_image(&self) -> XCapResult<RgbaImage> {
         capture::capture_window_sync(self.window_id, self.width, self.height)
     }
+
+    /// Capture a sub-region of the window, cropped to `rect`
+    ///
+    /// `rect` is in the same logical units as [`Window::width`]/
+    /// [`Window::height`]. Cropping happens via the stream configuration's
+    /// source rect before pixel copy, rather than capturing the full window
+    /// and slicing it afterwards.
+    pub fn capture_region(&self, rect: CaptureRect) -> XCapResult<RgbaImage> {
+        let cg_rect = capture::validate_capture_rect(rect, self.width, self.height)?;
+        capture::capture_window_region_sync(self.window_id, cg_rect)
+    }
+
+    /// Start a continuous capture stream of this window backed by `SCStream`.
+    ///
+    /// Frames are delivered over the returned channel rather than grabbed
+    /// one at a time. Dropping the returned [`Stream`] *or* its
+    /// [`FrameReceiver`] stops delivery: dropping the `Stream` tears down
+    /// `SCStream` immediately, while dropping just the receiver is detected
+    /// the next time a frame would be delivered. If the consumer falls
+    /// behind, the oldest queued frame is dropped in favor of the newest
+    /// instead of buffering without bound.
+    pub fn start_stream(&self, config: StreamConfig) -> XCapResult<(Stream, FrameReceiver)> {
+        capture::start_window_stream(self.window_id, &config)
+    }
+
+    /// Start a continuous capture stream of this window, delivering frames to `callback`.
+    ///
+    /// `callback` runs on SCK's delegate queue, not the caller's thread.
+    pub fn start_stream_with_callback<F>(&self, config: StreamConfig, callback: F) -> XCapResult<Stream>
+    where
+        F: FnMut(Frame) + Send + 'static,
+    {
+        capture::start_window_stream_with_callback(self.window_id, &config, callback)
+    }
+}
+
+/// Area of intersection (in logical-coordinate px²) between `window`'s frame and `monitor`'s frame
+fn overlap_area(window: &Window, monitor: &Monitor) -> i64 {
+    let window_left = window.x as i64;
+    let window_top = window.y as i64;
+    let window_right = window_left + window.width as i64;
+    let window_bottom = window_top + window.height as i64;
+
+    let monitor_left = monitor.x() as i64;
+    let monitor_top = monitor.y() as i64;
+    let monitor_right = monitor_left + monitor.logical_width() as i64;
+    let monitor_bottom = monitor_top + monitor.logical_height() as i64;
+
+    let overlap_width = (window_right.min(monitor_right) - window_left.max(monitor_left)).max(0);
+    let overlap_height = (window_bottom.min(monitor_bottom) - window_top.max(monitor_top)).max(0);
+
+    overlap_width * overlap_height
 }
 
 #[cfg(test)]
@@ -323,4 +501,56 @@ mod tests {
         // We just check it returns a result, not panics
         let _ = result;
     }
+
+    #[test]
+    fn test_frame_matches_within_tolerance() {
+        assert!(Window::frame_matches(1918, 1078, 1920, 1080));
+        assert!(!Window::frame_matches(1000, 1080, 1920, 1080));
+    }
+
+    #[test]
+    fn test_overlap_area_picks_largest_monitor() {
+        let window = Window {
+            window_id: 1,
+            app_name: "App".to_string(),
+            title: "Title".to_string(),
+            pid: 1,
+            x: 1800,
+            y: 0,
+            width: 400,
+            height: 400,
+            is_on_screen: true,
+            is_app_active: false,
+            window_layer: 0,
+        };
+
+        // Primary monitor: 0..1920. Secondary: 1920..3840.
+        // The window (1800..2200) overlaps 120px into the primary and
+        // 280px into the secondary, so the secondary should win.
+        let primary = crate::monitor::test_monitor(0, 0, 1920, 1080);
+        let secondary = crate::monitor::test_monitor(1920, 0, 1920, 1080);
+
+        assert!(overlap_area(&window, &secondary) > overlap_area(&window, &primary));
+    }
+
+    #[test]
+    fn test_overlap_area_offscreen_window() {
+        let window = Window {
+            window_id: 1,
+            app_name: "App".to_string(),
+            title: "Title".to_string(),
+            pid: 1,
+            x: -5000,
+            y: -5000,
+            width: 100,
+            height: 100,
+            is_on_screen: true,
+            is_app_active: false,
+            window_layer: 0,
+        };
+
+        let monitor = crate::monitor::test_monitor(0, 0, 1920, 1080);
+
+        assert_eq!(overlap_area(&window, &monitor), 0);
+    }
 }